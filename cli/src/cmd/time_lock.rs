@@ -4,6 +4,7 @@ use crate::bindings::time_lock::{
 use crate::cmd::utils::Bytes;
 use async_recursion::async_recursion;
 use ethers::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter, Result};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -62,6 +63,40 @@ pub enum Proposal {
         salt: H256,
         #[structopt(about = "Delay time to execute the proposal, should be larger than minDelay")]
         delay: U256,
+        #[structopt(long, about = "Dry-run each call via eth_call before producing calldata.")]
+        simulate: bool,
+        #[structopt(long, about = "Block to simulate against (defaults to latest).")]
+        simulate_block: Option<U64>,
+    },
+    #[structopt(about = "Schedule an proposal containing a batch of transactions.")]
+    ScheduleBatch {
+        #[structopt(
+            long = "target",
+            about = "The addresses of the smart contracts that the timelock should operate on."
+        )]
+        targets: Vec<Address>,
+        #[structopt(
+            long = "value",
+            about = "In wei, that should be sent with each transaction. Most of the time this will be 0."
+        )]
+        values: Vec<U256>,
+        #[structopt(
+            long = "data",
+            about = "Containing the encoded function selector and parameters of each call by abi.encode."
+        )]
+        data: Vec<Bytes>,
+        #[structopt(about = "That specifies a dependency between operations.")]
+        predecessor: H256,
+        #[structopt(
+            about = "Used to disambiguate two otherwise identical proposals. This can be any random value."
+        )]
+        salt: H256,
+        #[structopt(about = "Delay time to execute the proposal, should be larger than minDelay")]
+        delay: U256,
+        #[structopt(long, about = "Dry-run each call via eth_call before producing calldata.")]
+        simulate: bool,
+        #[structopt(long, about = "Block to simulate against (defaults to latest).")]
+        simulate_block: Option<U64>,
     },
     #[structopt(about = "Cancel an proposal.")]
     Cancel {
@@ -88,6 +123,48 @@ pub enum Proposal {
             about = "Used to disambiguate two otherwise identical proposals. This can be any random value."
         )]
         salt: H256,
+        #[structopt(long, about = "Dry-run the call via eth_call and check readiness first.")]
+        simulate: bool,
+        #[structopt(long, about = "Block to simulate against (defaults to latest).")]
+        simulate_block: Option<U64>,
+    },
+    #[structopt(about = "Watch a pending proposal's ETA, optionally auto-executing when ready.")]
+    Watch {
+        #[structopt(about = "Proposal ID")]
+        id: H256,
+        #[structopt(default_value = "0x65c0c")]
+        #[structopt(long, short)]
+        from_block: U64,
+        #[structopt(long, about = "Submit the execute call as soon as the proposal is ready.")]
+        auto_execute: bool,
+        #[structopt(long, about = "Salt used when scheduling; required for --auto-execute.")]
+        salt: Option<H256>,
+        #[structopt(long, default_value = "12", about = "Polling interval in seconds.")]
+        interval: u64,
+    },
+    #[structopt(about = "Execute an (ready) proposal containing a batch of transactions.")]
+    ExecuteBatch {
+        #[structopt(
+            long = "target",
+            about = "The addresses of the smart contracts that the timelock should operate on."
+        )]
+        targets: Vec<Address>,
+        #[structopt(
+            long = "value",
+            about = "In wei, that should be sent with each transaction. Most of the time this will be 0."
+        )]
+        values: Vec<U256>,
+        #[structopt(
+            long = "data",
+            about = "Containing the encoded function selector and parameters of each call by abi.encode."
+        )]
+        data: Vec<Bytes>,
+        #[structopt(about = "That specifies a dependency between operations.")]
+        predecessor: H256,
+        #[structopt(
+            about = "Used to disambiguate two otherwise identical proposals. This can be any random value."
+        )]
+        salt: H256,
     },
 }
 
@@ -124,68 +201,161 @@ pub enum ProposalStatus {
 }
 
 #[derive(Hash, Clone, Debug, Eq, PartialEq)]
-pub struct ProposalItem {
-    id: [u8; 32],
+pub struct ProposalCall {
     index: U256,
     target: Address,
     value: U256,
     data: ethers::prelude::Bytes,
+}
+
+impl ProposalCall {
+    fn from(filter: &CallScheduledFilter) -> Self {
+        ProposalCall {
+            index: filter.index,
+            target: filter.target,
+            value: filter.value,
+            data: filter.data.clone(),
+        }
+    }
+}
+
+#[derive(Hash, Clone, Debug, Eq, PartialEq)]
+pub struct ProposalItem {
+    id: [u8; 32],
     predecessor: [u8; 32],
     delay: U256,
     status: ProposalStatus,
+    calls: Vec<ProposalCall>,
 }
 
 impl ProposalItem {
     fn from(filter: &CallScheduledFilter) -> Self {
         ProposalItem {
             id: filter.id,
-            index: filter.index,
-            target: filter.target,
-            value: filter.value,
-            data: filter.data.clone(),
             predecessor: filter.predecessor,
             delay: filter.delay,
             status: ProposalStatus::Pending,
+            calls: vec![ProposalCall::from(filter)],
         }
     }
+
+    // A batch emits one `CallScheduled` per element, all sharing the same `id`
+    // but with a distinct `index`; collect each into the same proposal.
+    fn push(&mut self, filter: &CallScheduledFilter) {
+        self.calls.push(ProposalCall::from(filter));
+    }
 }
 
 impl Display for ProposalItem {
     fn fmt(&self, f: &mut Formatter) -> Result {
         write!(
             f,
-            "id: {}\nindex: {}\ntarget: {:?}\nvalue: {}\ndata: {}\npredecessor: {}\nstatus: {:?}",
+            "id: {}\npredecessor: {}\ndelay: {}\nstatus: {:?}",
             hex::encode(self.id),
-            self.index,
-            self.target,
-            self.value,
-            self.data,
             hex::encode(self.predecessor),
+            self.delay,
             self.status
-        )
+        )?;
+        for call in &self.calls {
+            write!(
+                f,
+                "\nindex: {}\ntarget: {:?}\nvalue: {}\ndata: {}",
+                call.index, call.target, call.value, call.data
+            )?;
+        }
+        Ok(())
     }
 }
 
+// The fully-qualified client the timelock bindings are parameterised over.
+pub type TimeLockClient = SignerMiddleware<Provider<Http>, Wallet<k256::ecdsa::SigningKey>>;
+
+// A provider-only client for read-only commands; no signing key is required
+// to construct one.
+pub type TimeLockReadClient = Provider<Http>;
+
+// Serialize a single `(to, value, data)` call as a Safe Transaction Builder
+// batch so a multisig can import and co-sign it instead of pasting hex.
+fn write_safe_batch(
+    path: &std::path::Path,
+    chain_id: u64,
+    to: Address,
+    calldata: &ethers::prelude::Bytes,
+) -> eyre::Result<()> {
+    let batch = serde_json::json!({
+        "version": "1.0",
+        "chainId": chain_id.to_string(),
+        "createdAt": timestamp() * 1000,
+        "meta": { "name": "TimeLock batch" },
+        "transactions": [{
+            "to": format!("{:?}", to),
+            "value": "0",
+            "data": format!("{}", calldata),
+        }],
+    });
+    std::fs::write(path, serde_json::to_string_pretty(&batch)?)?;
+    Ok(())
+}
+
+// Either export the prepared call as a Safe batch (`--export-safe`), submit it
+// on-chain (`--send`), or fall back to printing the hex calldata for an external
+// relayer to broadcast.
+async fn broadcast_or_print(
+    time_lock: &TimeLockContract<TimeLockClient>,
+    call: ContractCall<TimeLockClient, ()>,
+    send: bool,
+    export_safe: Option<&std::path::Path>,
+) -> eyre::Result<()> {
+    if send && export_safe.is_some() {
+        eyre::bail!("cannot combine --send and --export-safe");
+    }
+    let calldata = call.calldata().unwrap();
+    if let Some(path) = export_safe {
+        let chain_id = time_lock.client().get_chainid().await?.as_u64();
+        write_safe_batch(path, chain_id, time_lock.address(), &calldata)?;
+        println!("exported Safe batch to {}", path.display());
+        return Ok(());
+    }
+    if !send {
+        println!("{}", calldata);
+        return Ok(());
+    }
+    // Estimate gas first so a revert (e.g. the signer lacking the PROPOSER /
+    // EXECUTOR role) is reported with its reason instead of a raw RPC error.
+    let gas = call
+        .estimate_gas()
+        .await
+        .map_err(|err| eyre::eyre!("call would revert: {}", err))?;
+    println!("estimated gas: {}", gas);
+    let pending = call.send().await?;
+    println!("tx: {:?}", *pending);
+    if let Some(receipt) = pending.confirmations(1).await? {
+        let status = receipt.status.map(|s| s.as_u64()).unwrap_or_default();
+        println!("status: {}", status);
+    }
+    Ok(())
+}
+
 impl TimeLock {
-    pub async fn run(self) -> eyre::Result<()> {
+    pub async fn run(self, network: Network, send: bool, export_safe: Option<std::path::PathBuf>) -> eyre::Result<()> {
         match self {
             TimeLock::MinDelay => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_call(network).await?;
                 let min_delay = time_lock.get_min_delay().call().await?;
                 println!("{}", min_delay);
             }
-            TimeLock::Proposals(_p) => _p.run().await?,
-            TimeLock::Roles(_r) => _r.run().await?,
+            TimeLock::Proposals(_p) => _p.run(network, send, export_safe).await?,
+            TimeLock::Roles(_r) => _r.run(network, send, export_safe).await?,
         }
         Ok(())
     }
 }
 
 impl Role {
-    pub async fn run(self) -> eyre::Result<()> {
+    pub async fn run(self, network: Network, send: bool, export_safe: Option<std::path::PathBuf>) -> eyre::Result<()> {
         match self {
             Role::IsAdmin { account } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_call(network).await?;
                 let timelock_admin_role = time_lock.timelock_admin_role().call().await?;
                 let is = time_lock
                     .has_role(timelock_admin_role, account)
@@ -194,19 +364,19 @@ impl Role {
                 println!("{}", is);
             }
             Role::IsProposer { account } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_call(network).await?;
                 let proposer_role = time_lock.proposer_role().call().await?;
                 let is = time_lock.has_role(proposer_role, account).call().await?;
                 println!("{}", is);
             }
             Role::IsExecutor { account } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_call(network).await?;
                 let executor_role = time_lock.executor_role().call().await?;
                 let is = time_lock.has_role(executor_role, account).call().await?;
                 println!("{}", is);
             }
             Role::Grant { role, account } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_send(network).await?;
                 let role = if role == 1 {
                     time_lock.timelock_admin_role().call().await?
                 } else if role == 2 {
@@ -216,11 +386,10 @@ impl Role {
                 } else {
                     panic!("unexpect role");
                 };
-                let calldata = time_lock.grant_role(role, account).calldata().unwrap();
-                println!("{}", calldata);
+                broadcast_or_print(&time_lock, time_lock.grant_role(role, account), send, export_safe.as_deref()).await?;
             }
             Role::Revoke { role, account } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_send(network).await?;
                 let role = if role == 1 {
                     time_lock.timelock_admin_role().call().await?
                 } else if role == 2 {
@@ -230,8 +399,7 @@ impl Role {
                 } else {
                     panic!("unexpect role");
                 };
-                let calldata = time_lock.revoke_role(role, account).calldata().unwrap();
-                println!("{}", calldata);
+                broadcast_or_print(&time_lock, time_lock.revoke_role(role, account), send, export_safe.as_deref()).await?;
             }
         }
         Ok(())
@@ -239,7 +407,7 @@ impl Role {
 }
 
 impl Proposal {
-    pub async fn run(self) -> eyre::Result<()> {
+    pub async fn run(self, network: Network, send: bool, export_safe: Option<std::path::PathBuf>) -> eyre::Result<()> {
         match self {
             Proposal::List {
                 from_block,
@@ -250,7 +418,7 @@ impl Proposal {
                 no_cancel,
             } => {
                 load_proposals(
-                    from_block, to_block, no_done, no_ready, no_pending, no_cancel,
+                    network, from_block, to_block, no_done, no_ready, no_pending, no_cancel,
                 )
                 .await?;
             }
@@ -261,26 +429,70 @@ impl Proposal {
                 predecessor,
                 salt,
                 delay,
+                simulate,
+                simulate_block,
             } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_send(network).await?;
                 let calldata = ethers::prelude::Bytes::from(data.0);
-                let payload = time_lock
-                    .schedule(
-                        target,
-                        value,
-                        calldata,
-                        *predecessor.as_fixed_bytes(),
-                        *salt.as_fixed_bytes(),
-                        delay,
+                if simulate {
+                    simulate_calls(
+                        &time_lock,
+                        &[(target, value, calldata.clone())],
+                        simulate_block,
                     )
-                    .calldata()
-                    .unwrap();
-                println!("{}", payload);
+                    .await?;
+                }
+                let call = time_lock.schedule(
+                    target,
+                    value,
+                    calldata,
+                    *predecessor.as_fixed_bytes(),
+                    *salt.as_fixed_bytes(),
+                    delay,
+                );
+                broadcast_or_print(&time_lock, call, send, export_safe.as_deref()).await?;
+            }
+            Proposal::ScheduleBatch {
+                targets,
+                values,
+                data,
+                predecessor,
+                salt,
+                delay,
+                simulate,
+                simulate_block,
+            } => {
+                check_batch_arity(&targets, &values, &data)?;
+                let time_lock = init_timelock_send(network).await?;
+                let payloads: Vec<ethers::prelude::Bytes> = data
+                    .into_iter()
+                    .map(|d| ethers::prelude::Bytes::from(d.0))
+                    .collect();
+                let id = hash_operation_batch(&targets, &values, &payloads, &predecessor, &salt);
+                println!("id: {}", hex::encode(id));
+                if simulate {
+                    let calls: Vec<_> = targets
+                        .iter()
+                        .cloned()
+                        .zip(values.iter().cloned())
+                        .zip(payloads.iter().cloned())
+                        .map(|((t, v), d)| (t, v, d))
+                        .collect();
+                    simulate_calls(&time_lock, &calls, simulate_block).await?;
+                }
+                let call = time_lock.schedule_batch(
+                    targets,
+                    values,
+                    payloads,
+                    *predecessor.as_fixed_bytes(),
+                    *salt.as_fixed_bytes(),
+                    delay,
+                );
+                broadcast_or_print(&time_lock, call, send, export_safe.as_deref()).await?;
             }
             Proposal::Cancel { id } => {
-                let time_lock = init_timelock_call().await?;
-                let calldata = time_lock.cancel(*id.as_fixed_bytes()).calldata().unwrap();
-                println!("{}", calldata);
+                let time_lock = init_timelock_send(network).await?;
+                broadcast_or_print(&time_lock, time_lock.cancel(*id.as_fixed_bytes()), send, export_safe.as_deref()).await?;
             }
             Proposal::Execute {
                 target,
@@ -288,27 +500,393 @@ impl Proposal {
                 data,
                 predecessor,
                 salt,
+                simulate,
+                simulate_block,
             } => {
-                let time_lock = init_timelock_call().await?;
+                let time_lock = init_timelock_send(network).await?;
                 let calldata = ethers::prelude::Bytes::from(data.0);
-                let payload = time_lock
-                    .execute(
-                        target,
-                        value,
-                        calldata,
-                        *predecessor.as_fixed_bytes(),
-                        *salt.as_fixed_bytes(),
+                if simulate {
+                    // Catch ordering failures (not ready, predecessor pending)
+                    // before paying gas, then dry-run the underlying call.
+                    let id = time_lock
+                        .hash_operation(
+                            target,
+                            value,
+                            calldata.clone(),
+                            *predecessor.as_fixed_bytes(),
+                            *salt.as_fixed_bytes(),
+                        )
+                        .call()
+                        .await?;
+                    verify_execute_ready(&time_lock, id, *predecessor.as_fixed_bytes()).await?;
+                    simulate_calls(
+                        &time_lock,
+                        &[(target, value, calldata.clone())],
+                        simulate_block,
                     )
-                    .calldata()
-                    .unwrap();
-                println!("{}", payload);
+                    .await?;
+                }
+                let call = time_lock.execute(
+                    target,
+                    value,
+                    calldata,
+                    *predecessor.as_fixed_bytes(),
+                    *salt.as_fixed_bytes(),
+                );
+                broadcast_or_print(&time_lock, call, send, export_safe.as_deref()).await?;
+            }
+            Proposal::ExecuteBatch {
+                targets,
+                values,
+                data,
+                predecessor,
+                salt,
+            } => {
+                check_batch_arity(&targets, &values, &data)?;
+                let time_lock = init_timelock_send(network).await?;
+                let payloads: Vec<ethers::prelude::Bytes> = data
+                    .into_iter()
+                    .map(|d| ethers::prelude::Bytes::from(d.0))
+                    .collect();
+                let call = time_lock.execute_batch(
+                    targets,
+                    values,
+                    payloads,
+                    *predecessor.as_fixed_bytes(),
+                    *salt.as_fixed_bytes(),
+                );
+                broadcast_or_print(&time_lock, call, send, export_safe.as_deref()).await?;
+            }
+            Proposal::Watch {
+                id,
+                from_block,
+                auto_execute,
+                salt,
+                interval,
+            } => {
+                watch_proposal(network, id, from_block, auto_execute, salt, interval).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Track a proposal's ETA, printing a live countdown, and optionally submit its
+// `execute` call the moment the delay elapses and the predecessor is satisfied.
+// The OZ timelock encodes state in `get_timestamp`: 0 = unknown/cancelled,
+// 1 (`_DONE_TIMESTAMP`) = executed, anything larger is the ready-at timestamp.
+async fn watch_proposal(
+    network: Network,
+    id: H256,
+    from_block: U64,
+    auto_execute: bool,
+    salt: Option<H256>,
+    interval: u64,
+) -> eyre::Result<()> {
+    if auto_execute && salt.is_none() {
+        eyre::bail!("--salt is required for --auto-execute");
+    }
+    // Polling only ever reads; the signer is only needed at the moment
+    // `--auto-execute` actually submits the execute call, further down.
+    let time_lock = init_timelock_call(network.clone()).await?;
+    let id_bytes = *id.as_fixed_bytes();
+
+    // Resolve the scheduled call(s) once so we can reconstruct the execution.
+    let head = time_lock.client().get_block_number().await?;
+    let events = load_events(time_lock.clone(), &from_block, &head).await;
+    let mut calls: Vec<CallScheduledFilter> = events
+        .iter()
+        .filter_map(|(e, _)| match e {
+            TimeLockEvents::CallScheduledFilter(d) if d.id == id_bytes => Some(d.clone()),
+            _ => None,
+        })
+        .collect();
+    calls.sort_by(|a, b| a.index.cmp(&b.index));
+    if calls.is_empty() {
+        eyre::bail!("proposal {} not found in events", hex::encode(id_bytes));
+    }
+    let predecessor = calls[0].predecessor;
+
+    // Verify --salt actually reproduces the watched id before polling starts,
+    // rather than only discovering a wrong salt once the delay has elapsed
+    // and the constructed execute call reverts (mirrors Execute --simulate,
+    // which hashes the operation and checks that id with verify_execute_ready).
+    let salt_bytes = if auto_execute {
+        let salt_bytes = *salt.expect("--salt validated at startup").as_fixed_bytes();
+        let expected_id = if calls.len() == 1 {
+            let c = &calls[0];
+            time_lock
+                .hash_operation(c.target, c.value, c.data.clone(), predecessor, salt_bytes)
+                .call()
+                .await?
+        } else {
+            let targets = calls.iter().map(|c| c.target).collect();
+            let values = calls.iter().map(|c| c.value).collect();
+            let payloads = calls.iter().map(|c| c.data.clone()).collect();
+            time_lock
+                .hash_operation_batch(targets, values, payloads, predecessor, salt_bytes)
+                .call()
+                .await?
+        };
+        if expected_id != id_bytes {
+            eyre::bail!(
+                "--salt does not reproduce proposal {}: got {}",
+                hex::encode(id_bytes),
+                hex::encode(expected_id)
+            );
+        }
+        Some(salt_bytes)
+    } else {
+        None
+    };
+
+    loop {
+        let ts = time_lock.get_timestamp(id_bytes).call().await?;
+        let now = timestamp();
+        if ts.as_u64() == 1 {
+            println!("status: {:?}", ProposalStatus::Executed);
+            break;
+        }
+        if ts.is_zero() {
+            println!("status: {:?}", ProposalStatus::Cancelled);
+            break;
+        }
+        if ts.as_u64() > now {
+            println!(
+                "status: {:?}  eta: {}  remaining: {}s",
+                ProposalStatus::Pending,
+                ts,
+                ts.as_u64() - now
+            );
+        } else {
+            println!("status: {:?}  eta: {}", ProposalStatus::Ready, ts);
+            if auto_execute {
+                if predecessor != [0u8; 32]
+                    && !time_lock.is_operation_done(predecessor).call().await?
+                {
+                    println!(
+                        "waiting for predecessor {} to execute",
+                        hex::encode(predecessor)
+                    );
+                    tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+                    continue;
+                }
+                // Presence and correctness already validated up-front.
+                let salt = salt_bytes.expect("--salt validated at startup");
+                let send_time_lock = init_timelock_send(network.clone()).await?;
+                let call = if calls.len() == 1 {
+                    let c = &calls[0];
+                    send_time_lock.execute(c.target, c.value, c.data.clone(), predecessor, salt)
+                } else {
+                    let targets = calls.iter().map(|c| c.target).collect();
+                    let values = calls.iter().map(|c| c.value).collect();
+                    let payloads = calls.iter().map(|c| c.data.clone()).collect();
+                    send_time_lock.execute_batch(targets, values, payloads, predecessor, salt)
+                };
+                broadcast_or_print(&send_time_lock, call, true, None).await?;
+                break;
             }
         }
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+    }
+    Ok(())
+}
+
+// `ScheduleBatch`/`ExecuteBatch` take `targets`/`values`/`data` as independent
+// `Vec`s; a mismatched length would otherwise only surface as a silently
+// truncated `--simulate` (zip stops at the shortest) followed by an on-chain
+// revert of the full, mismatched batch.
+fn check_batch_arity(targets: &[Address], values: &[U256], data: &[Bytes]) -> eyre::Result<()> {
+    if targets.len() != values.len() || values.len() != data.len() {
+        eyre::bail!(
+            "mismatched batch arity: {} target(s), {} value(s), {} data(s)",
+            targets.len(),
+            values.len(),
+            data.len()
+        );
+    }
+    Ok(())
+}
+
+// The batch operation id matches the contract's `hashOperationBatch`:
+// keccak256(abi.encode(targets[], values[], payloads[], predecessor, salt)).
+pub fn hash_operation_batch(
+    targets: &[Address],
+    values: &[U256],
+    payloads: &[ethers::prelude::Bytes],
+    predecessor: &H256,
+    salt: &H256,
+) -> [u8; 32] {
+    use ethers::abi::Token;
+    let encoded = ethers::abi::encode(&[
+        Token::Array(targets.iter().map(|t| Token::Address(*t)).collect()),
+        Token::Array(values.iter().map(|v| Token::Uint(*v)).collect()),
+        Token::Array(
+            payloads
+                .iter()
+                .map(|p| Token::Bytes(p.to_vec()))
+                .collect(),
+        ),
+        Token::FixedBytes(predecessor.as_bytes().to_vec()),
+        Token::FixedBytes(salt.as_bytes().to_vec()),
+    ]);
+    ethers::utils::keccak256(encoded)
+}
+
+// Dry-run each `(target, value, data)` via `eth_call` from the timelock address
+// and print a per-call success/revert table. A revert aborts the command instead
+// of being hidden behind a later on-chain failure.
+async fn simulate_calls(
+    time_lock: &TimeLockContract<TimeLockClient>,
+    calls: &[(Address, U256, ethers::prelude::Bytes)],
+    block: Option<U64>,
+) -> eyre::Result<()> {
+    let from = time_lock.address();
+    let client = time_lock.client();
+    let block_id: Option<BlockId> = block.map(|b| BlockId::Number(b.into()));
+    println!("{:<4} {:<44} result", "idx", "target");
+    let mut reverted = 0;
+    for (i, (target, value, data)) in calls.iter().enumerate() {
+        let tx: TypedTransaction = Eip1559TransactionRequest::new()
+            .from(from)
+            .to(*target)
+            .value(*value)
+            .data(data.clone())
+            .into();
+        match client.call(&tx, block_id).await {
+            Ok(_) => println!("{:<4} {:?} ok", i, target),
+            Err(err) => {
+                println!("{:<4} {:?} revert: {}", i, target, err);
+                reverted += 1;
+            }
+        }
+    }
+    if reverted > 0 {
+        eyre::bail!("{} of {} simulated call(s) would revert", reverted, calls.len());
+    }
+    Ok(())
+}
+
+// Check that a proposal is actually executable: scheduled, its ETA has passed,
+// and any predecessor operation is already done.
+async fn verify_execute_ready(
+    time_lock: &TimeLockContract<TimeLockClient>,
+    id: [u8; 32],
+    predecessor: [u8; 32],
+) -> eyre::Result<()> {
+    let ts = time_lock.get_timestamp(id).call().await?;
+    let now = timestamp();
+    // `get_timestamp` returns 0 for "never scheduled" and 1 (`_DONE_TIMESTAMP`)
+    // for "already executed"; both mean there is nothing left to execute here.
+    if ts.is_zero() {
+        eyre::bail!("proposal {} is not scheduled", hex::encode(id));
+    }
+    if ts.as_u64() == 1 {
+        eyre::bail!("proposal {} has already been executed", hex::encode(id));
+    }
+    if ts.as_u64() > now {
+        eyre::bail!("proposal {} not ready: eta {} > now {}", hex::encode(id), ts, now);
+    }
+    if predecessor != [0u8; 32] && !time_lock.is_operation_done(predecessor).call().await? {
+        eyre::bail!(
+            "predecessor {} has not been executed",
+            hex::encode(predecessor)
+        );
+    }
+    Ok(())
+}
+
+// A decoded event reduced to the fields the proposal fold needs, in a form that
+// round-trips through the on-disk cache.
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedEvent {
+    Scheduled {
+        id: [u8; 32],
+        index: U256,
+        target: Address,
+        value: U256,
+        data: ethers::prelude::Bytes,
+        predecessor: [u8; 32],
+        delay: U256,
+    },
+    Executed {
+        id: [u8; 32],
+    },
+    Cancelled {
+        id: [u8; 32],
+    },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLog {
+    block_number: u64,
+    event: CachedEvent,
+}
+
+impl CachedLog {
+    fn from(event: &TimeLockEvents, meta: &LogMeta) -> Option<Self> {
+        let event = match event {
+            TimeLockEvents::CallScheduledFilter(d) => CachedEvent::Scheduled {
+                id: d.id,
+                index: d.index,
+                target: d.target,
+                value: d.value,
+                data: d.data.clone(),
+                predecessor: d.predecessor,
+                delay: d.delay,
+            },
+            TimeLockEvents::CallExecutedFilter(d) => CachedEvent::Executed { id: d.id },
+            TimeLockEvents::CancelledFilter(d) => CachedEvent::Cancelled { id: d.id },
+            _ => return None,
+        };
+        Some(CachedLog {
+            block_number: meta.block_number.as_u64(),
+            event,
+        })
+    }
+}
+
+// The per-(chain, contract) cache entry: the fully-synced window and its logs.
+#[derive(Default, Serialize, Deserialize)]
+struct CacheEntry {
+    synced_from: u64,
+    synced_to: u64,
+    logs: Vec<CachedLog>,
+}
+
+// A small sled-backed store so repeated `proposal list` calls only query the new
+// block range instead of rescanning the whole history on every invocation.
+struct EventCache {
+    db: sled::Db,
+}
+
+impl EventCache {
+    fn open() -> eyre::Result<Self> {
+        let dir = std::env::var("TIMELOCK_CACHE_DIR")
+            .unwrap_or_else(|_| ".timelock_cache".to_string());
+        Ok(EventCache { db: sled::open(dir)? })
+    }
+
+    fn key(chain_id: u64, address: Address) -> String {
+        format!("{}:{:?}", chain_id, address)
+    }
+
+    fn load(&self, key: &str) -> eyre::Result<CacheEntry> {
+        match self.db.get(key)? {
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            None => Ok(CacheEntry::default()),
+        }
+    }
+
+    fn store(&self, key: &str, entry: &CacheEntry) -> eyre::Result<()> {
+        self.db.insert(key, serde_json::to_vec(entry)?)?;
+        self.db.flush()?;
         Ok(())
     }
 }
 
 pub async fn load_proposals(
+    network: Network,
     from_block: U64,
     to_block: Option<U64>,
     no_done: bool,
@@ -316,44 +894,99 @@ pub async fn load_proposals(
     no_pending: bool,
     no_cancel: bool,
 ) -> eyre::Result<()> {
-    let time_lock = init_timelock_call().await?;
-    let _to_block = if let Some(to_block) = to_block {
+    let time_lock = init_timelock_call(network).await?;
+    let head = if let Some(to_block) = to_block {
         to_block
     } else {
         time_lock.client().get_block_number().await.unwrap()
     };
     let now = timestamp();
+
+    // Fetch cached logs, then extend coverage with only the uncached ranges. The
+    // recursive bisection in `load_events` stays confined to those fresh ranges.
+    let chain_id = time_lock.client().get_chainid().await?.as_u64();
+    let cache = EventCache::open()?;
+    let cache_key = EventCache::key(chain_id, time_lock.address());
+    let mut entry = cache.load(&cache_key)?;
+
+    let mut fresh: Vec<CachedLog> = Vec::new();
+    if entry.synced_to == 0 {
+        let events = load_events(time_lock.clone(), &from_block, &head).await;
+        fresh.extend(events.iter().filter_map(|(e, m)| CachedLog::from(e, m)));
+        entry.synced_from = from_block.as_u64();
+        entry.synced_to = head.as_u64();
+    } else {
+        if from_block.as_u64() < entry.synced_from {
+            let upper = U64::from(entry.synced_from - 1);
+            let events = load_events(time_lock.clone(), &from_block, &upper).await;
+            fresh.extend(events.iter().filter_map(|(e, m)| CachedLog::from(e, m)));
+            entry.synced_from = from_block.as_u64();
+        }
+        if head.as_u64() > entry.synced_to {
+            let lower = U64::from(entry.synced_to + 1);
+            let events = load_events(time_lock.clone(), &lower, &head).await;
+            fresh.extend(events.iter().filter_map(|(e, m)| CachedLog::from(e, m)));
+            entry.synced_to = head.as_u64();
+        }
+    }
+    entry.logs.extend(fresh);
+    entry.logs.sort_by(|a, b| a.block_number.cmp(&b.block_number));
+    cache.store(&cache_key, &entry)?;
+
     let mut proposals: HashMap<[u8; 32], ProposalItem> = HashMap::new();
-    let mut events = load_events(time_lock.clone(), &from_block, &_to_block).await;
-    events.sort_by(|a, b| a.1.block_number.cmp(&b.1.block_number));
-    for event in events {
-        match &event.0 {
-            TimeLockEvents::CallScheduledFilter(data) => {
-                let mut proposal = ProposalItem::from(data);
-                let ts = time_lock.get_timestamp(proposal.id).call().await?;
-                if ts.as_u64() < now {
-                    proposal.status = ProposalStatus::Ready;
-                }
-                proposals.insert(data.id, proposal);
+    for log in entry
+        .logs
+        .iter()
+        .filter(|l| l.block_number >= from_block.as_u64() && l.block_number <= head.as_u64())
+    {
+        match &log.event {
+            CachedEvent::Scheduled {
+                id,
+                index,
+                target,
+                value,
+                data,
+                predecessor,
+                delay,
+            } => {
+                let filter = CallScheduledFilter {
+                    id: *id,
+                    index: *index,
+                    target: *target,
+                    value: *value,
+                    data: data.clone(),
+                    predecessor: *predecessor,
+                    delay: *delay,
+                };
+                let ts = time_lock.get_timestamp(*id).call().await?;
+                let status = if ts.as_u64() < now {
+                    ProposalStatus::Ready
+                } else {
+                    ProposalStatus::Pending
+                };
+                proposals
+                    .entry(*id)
+                    .and_modify(|p| p.push(&filter))
+                    .or_insert_with(|| {
+                        let mut proposal = ProposalItem::from(&filter);
+                        proposal.status = status;
+                        proposal
+                    });
             }
-            TimeLockEvents::CallExecutedFilter(data) => {
-                if proposals.contains_key(&data.id) {
-                    proposals.get_mut(&data.id).unwrap().status = ProposalStatus::Executed;
+            CachedEvent::Executed { id } => {
+                if proposals.contains_key(id) {
+                    proposals.get_mut(id).unwrap().status = ProposalStatus::Executed;
                 } else {
                     panic!("proposal not exist");
                 }
             }
-            TimeLockEvents::CancelledFilter(data) => {
-                if proposals.contains_key(&data.id) {
-                    proposals.get_mut(&data.id).unwrap().status = ProposalStatus::Cancelled;
+            CachedEvent::Cancelled { id } => {
+                if proposals.contains_key(id) {
+                    proposals.get_mut(id).unwrap().status = ProposalStatus::Cancelled;
                 } else {
                     panic!("proposal not exist");
                 }
             }
-            TimeLockEvents::MinDelayChangeFilter(_) => {}
-            TimeLockEvents::RoleAdminChangedFilter(_) => {}
-            TimeLockEvents::RoleGrantedFilter(_) => {}
-            TimeLockEvents::RoleRevokedFilter(_) => {}
         }
     }
     let mut statuses: HashSet<ProposalStatus> = [
@@ -395,7 +1028,7 @@ pub fn timestamp() -> u64 {
 
 #[async_recursion]
 pub async fn load_events(
-    contract: TimeLockContract<SignerMiddleware<Provider<Http>, Wallet<k256::ecdsa::SigningKey>>>,
+    contract: TimeLockContract<TimeLockReadClient>,
     from_block: &U64,
     to_block: &U64,
 ) -> Vec<(TimeLockEvents, LogMeta)> {
@@ -425,33 +1058,133 @@ pub async fn load_events(
     }
 }
 
+/// The network a command operates against. Selected with `--network`.
+/// Not a closed set: any name present in the config resolved by
+/// [`load_networks`] (built-in defaults, or `networks.toml`) is accepted, so
+/// a new network can be added without a source change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network(String);
+
+impl Default for Network {
+    fn default() -> Self {
+        Network("pangolin".to_string())
+    }
+}
+
+impl FromStr for Network {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Network(s.to_ascii_lowercase()))
+    }
+}
+
+impl Display for Network {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Resolved RPC endpoint and timelock address for a network.
+#[derive(Clone, Deserialize)]
+pub struct NetworkConfig {
+    pub rpc_url: String,
+    pub address: Address,
+}
+
+// Built-in defaults, kept in sync with the addresses the tool shipped with.
+// A network outside this set must come from the config file below.
+fn builtin_networks() -> HashMap<String, NetworkConfig> {
+    HashMap::from([
+        (
+            "pangolin".to_string(),
+            NetworkConfig {
+                rpc_url: "https://pangolin-rpc.darwinia.network".to_string(),
+                address: Address::from_str("0x4214611Be6cA4E337b37e192abF076F715Af4CaE").unwrap(),
+            },
+        ),
+        (
+            "crab".to_string(),
+            NetworkConfig {
+                rpc_url: "https://crab-rpc.darwinia.network".to_string(),
+                address: Address::from_str("0xED1d1d219f85Bc634f250db5e77E0330Cddc9b2a").unwrap(),
+            },
+        ),
+    ])
+}
+
+// A TOML file (path from `TIMELOCK_CONFIG`, defaulting to `networks.toml`) of
+// the form `[name]\nrpc_url = "..."\naddress = "0x..."`, one table per
+// network. Entries here add to, or override, the built-in defaults, so a new
+// network can be configured without touching the source.
+fn load_networks() -> eyre::Result<HashMap<String, NetworkConfig>> {
+    let mut networks = builtin_networks();
+    let path = std::env::var("TIMELOCK_CONFIG").unwrap_or_else(|_| "networks.toml".to_string());
+    if let Ok(contents) = std::fs::read_to_string(&path) {
+        let configured: HashMap<String, NetworkConfig> = toml::from_str(&contents)
+            .map_err(|err| eyre::eyre!("invalid network config {}: {}", path, err))?;
+        networks.extend(configured);
+    }
+    Ok(networks)
+}
+
+impl Network {
+    /// Resolve the endpoint and address for this network, letting
+    /// per-network env vars (`TIMELOCK_RPC_URL_<NETWORK>` /
+    /// `TIMELOCK_ADDRESS_<NETWORK>`) override the config file / built-in
+    /// defaults without clobbering the settings of any other network.
+    pub fn config(&self) -> eyre::Result<NetworkConfig> {
+        let mut networks = load_networks()?;
+        let mut config = networks
+            .remove(&self.0)
+            .ok_or_else(|| eyre::eyre!("unknown network '{}': add it to networks.toml", self.0))?;
+        let suffix = self.0.to_ascii_uppercase();
+        if let Ok(rpc_url) = std::env::var(format!("TIMELOCK_RPC_URL_{}", suffix)) {
+            config.rpc_url = rpc_url;
+        }
+        if let Ok(address) = std::env::var(format!("TIMELOCK_ADDRESS_{}", suffix)) {
+            config.address = Address::from_str(&address)?;
+        }
+        Ok(config)
+    }
+}
+
+// Load the signing key from `TIMELOCK_PRIVATE_KEY` or, failing that, a JSON V3
+// keystore at `TIMELOCK_KEYSTORE` unlocked with `TIMELOCK_KEYSTORE_PASSWORD`.
+// The key is never embedded in the source.
+fn load_wallet(chain_id: u64) -> eyre::Result<LocalWallet> {
+    let wallet = if let Ok(private_key) = std::env::var("TIMELOCK_PRIVATE_KEY") {
+        private_key.parse::<LocalWallet>()?
+    } else if let Ok(path) = std::env::var("TIMELOCK_KEYSTORE") {
+        let password = std::env::var("TIMELOCK_KEYSTORE_PASSWORD").unwrap_or_default();
+        LocalWallet::decrypt_keystore(path, password)?
+    } else {
+        eyre::bail!("no signing key: set TIMELOCK_PRIVATE_KEY or TIMELOCK_KEYSTORE");
+    };
+    Ok(wallet.with_chain_id(chain_id))
+}
+
+/// Build a read-only contract handle. No signing key is required; use this
+/// for commands that only ever call view functions or read past events.
 pub async fn init_timelock_call(
-) -> eyre::Result<TimeLockContract<SignerMiddleware<Provider<Http>, Wallet<k256::ecdsa::SigningKey>>>>
-{
-    Ok(init_timelock_send(
-        "380eb0f3d505f087e438eca80bc4df9a7faa24f868e69fc0440261a0fc0567dc".to_string(),
-    )
-    .await?)
+    network: Network,
+) -> eyre::Result<TimeLockContract<TimeLockReadClient>> {
+    let config = network.config()?;
+    let provider = Provider::<Http>::try_from(config.rpc_url.as_str())?;
+    let time_lock = TimeLockContract::new(config.address, Arc::new(provider));
+    Ok(time_lock)
 }
 
+/// Build a signer-backed contract handle for commands that may broadcast a
+/// transaction (requires `TIMELOCK_PRIVATE_KEY` or `TIMELOCK_KEYSTORE`).
 pub async fn init_timelock_send(
-    private_key: String,
-) -> eyre::Result<TimeLockContract<SignerMiddleware<Provider<Http>, Wallet<k256::ecdsa::SigningKey>>>>
-{
-    // let provider = Provider::<Http>::try_from("https://crab-rpc.darwinia.network")?;
-    let provider = Provider::<Http>::try_from("https://pangolin-rpc.darwinia.network")?;
-    let chain_id = provider.get_chainid().await.unwrap().as_u64();
-    let key = private_key
-        .parse::<LocalWallet>()
-        .unwrap()
-        .with_chain_id(chain_id);
-    let to = Address::from_str("0x4214611Be6cA4E337b37e192abF076F715Af4CaE")?;
-    // pangolin
-    // let to = Address::from_str("0x2401224012bAE7C2f217392665CA7abC16dCDE1e")?;
-    // crab
-    // let to = Address::from_str("0xED1d1d219f85Bc634f250db5e77E0330Cddc9b2a")?;
-    let client = SignerMiddleware::new(provider, key);
-    let client = Arc::new(client);
-    let time_lock = TimeLockContract::new(to, client);
+    network: Network,
+) -> eyre::Result<TimeLockContract<TimeLockClient>> {
+    let config = network.config()?;
+    let provider = Provider::<Http>::try_from(config.rpc_url.as_str())?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let key = load_wallet(chain_id)?;
+    let client = Arc::new(SignerMiddleware::new(provider, key));
+    let time_lock = TimeLockContract::new(config.address, client);
     Ok(time_lock)
 }
\ No newline at end of file